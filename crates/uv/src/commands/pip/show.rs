@@ -1,24 +1,75 @@
 use std::fmt::Write;
+use std::path::{Path, PathBuf};
 
 use anyhow::Result;
+use base64::Engine;
 use fs_err::File;
 use itertools::{Either, Itertools};
 use owo_colors::OwoColorize;
 use rustc_hash::FxHashMap;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
 
 use uv_cache::Cache;
 use uv_configuration::Preview;
 use uv_distribution_types::{Diagnostic, Name};
 use uv_fs::Simplified;
-use uv_install_wheel::read_record_file;
+use uv_install_wheel::{read_record_file, RecordEntry};
 use uv_installer::SitePackages;
 use uv_normalize::PackageName;
 use uv_python::{EnvironmentPreference, PythonEnvironment, PythonRequest};
+use uv_static::EnvVars;
 
-use crate::commands::ExitStatus;
 use crate::commands::pip::operations::report_target_environment;
+use crate::commands::ExitStatus;
 use crate::printer::Printer;
 
+/// The output format for `uv pip show`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub(crate) enum ShowFormat {
+    /// Display the distributions in a human-readable format.
+    #[default]
+    Text,
+    /// Display the distributions in a machine-readable JSON format.
+    Json,
+}
+
+/// A single distribution, as rendered in `--format json` output.
+#[derive(Debug, Serialize)]
+struct ShowReport {
+    name: String,
+    version: String,
+    location: String,
+    editable_project_location: Option<String>,
+    summary: Option<String>,
+    home_page: Option<String>,
+    author: Option<String>,
+    author_email: Option<String>,
+    license: Option<String>,
+    requires_python: Option<String>,
+    /// Only populated in `--verbose` mode.
+    project_urls: Option<Vec<String>>,
+    /// Only populated in `--verbose` mode.
+    classifiers: Option<Vec<String>>,
+    /// Only populated in `--verbose` mode.
+    entry_points: Option<Vec<String>>,
+    requires: Vec<String>,
+    required_by: Vec<String>,
+    required_by_tree: Option<Vec<RequiredByNode>>,
+    files: Option<Vec<String>>,
+    verify: Option<VerifyReport>,
+}
+
+/// A single node in the `--tree` reverse-dependency tree, as rendered in `--format json` output.
+#[derive(Debug, Serialize)]
+struct RequiredByNode {
+    name: String,
+    /// Whether this package is already an ancestor of itself in the tree, i.e., a cycle; if so,
+    /// `children` is always empty, matching the `(*)` marker in the text output.
+    cycle: bool,
+    children: Vec<RequiredByNode>,
+}
+
 /// Show information about one or more installed packages.
 pub(crate) fn pip_show(
     mut packages: Vec<PackageName>,
@@ -26,6 +77,12 @@ pub(crate) fn pip_show(
     python: Option<&str>,
     system: bool,
     files: bool,
+    verbose: bool,
+    verify: bool,
+    tree: bool,
+    all_environments: bool,
+    environments_root: Option<PathBuf>,
+    output_format: ShowFormat,
     cache: &Cache,
     printer: Printer,
     preview: Preview,
@@ -43,6 +100,19 @@ pub(crate) fn pip_show(
         return Ok(ExitStatus::Failure);
     }
 
+    if all_environments {
+        // Sort and deduplicate the packages, which are keyed by name.
+        packages.sort_unstable();
+        packages.dedup();
+        return pip_show_all_environments(
+            &packages,
+            environments_root.as_deref(),
+            output_format,
+            cache,
+            printer,
+        );
+    }
+
     // Detect the current Python interpreter.
     let environment = PythonEnvironment::find(
         &python.map(PythonRequest::parse).unwrap_or_default(),
@@ -109,8 +179,10 @@ pub(crate) fn pip_show(
             );
         }
     }
-    // For Required-by field
-    if !requires_map.is_empty() {
+    // For Required-by field. When `--tree` is set, the tree is built from the full
+    // reverse-dependency graph, so collect every installed package's requirements even if none
+    // of the packages we're showing had readable metadata.
+    if !requires_map.is_empty() || tree {
         for installed in site_packages.iter() {
             if requires_map.contains_key(installed.name()) {
                 continue;
@@ -127,73 +199,286 @@ pub(crate) fn pip_show(
         }
     }
 
-    // Print the information for each package.
-    for (i, distribution) in distributions.iter().enumerate() {
-        if i > 0 {
-            // Print a separator between packages.
-            writeln!(printer.stdout(), "---")?;
+    // Compute the packages that require each distribution, i.e., the reverse of `requires_map`.
+    let required_by = |name: &PackageName| -> Vec<PackageName> {
+        requires_map
+            .iter()
+            .filter(|(other, pkgs)| **other != name && pkgs.iter().any(|pkg| pkg == name))
+            .map(|(other, _)| (**other).clone())
+            .sorted_unstable()
+            .dedup()
+            .collect_vec()
+    };
+
+    let mut verification_failed = false;
+
+    if output_format == ShowFormat::Json {
+        let mut reports = Vec::with_capacity(distributions.len());
+        for distribution in &distributions {
+            // Both `--files` and `--verify` need the RECORD; read it once and reuse it.
+            let record = if files || verify {
+                let path = distribution.install_path().join("RECORD");
+                Some(read_record_file(&mut File::open(path)?)?)
+            } else {
+                None
+            };
+
+            let files = files.then(|| {
+                record
+                    .as_ref()
+                    .into_iter()
+                    .flatten()
+                    .map(|entry| entry.path.clone())
+                    .collect_vec()
+            });
+
+            let verify_report = if verify {
+                let report = verify_record(
+                    distribution.install_path(),
+                    record.as_deref().unwrap_or_default(),
+                )?;
+                if report.is_failure() {
+                    verification_failed = true;
+                }
+                Some(report)
+            } else {
+                None
+            };
+
+            // Read the metadata again for the core-metadata fields, mirroring the text output;
+            // the read made above for the `Requires` field is not reused.
+            let metadata = distribution.metadata().ok();
+            let (project_urls, classifiers) = if verbose {
+                (
+                    metadata.as_ref().map(|metadata| {
+                        metadata
+                            .project_urls
+                            .iter()
+                            .map(|(name, url)| format!("{name}, {url}"))
+                            .collect_vec()
+                    }),
+                    metadata
+                        .as_ref()
+                        .map(|metadata| metadata.classifiers.clone()),
+                )
+            } else {
+                (None, None)
+            };
+            let entry_points = if verbose {
+                let path = distribution.install_path().join("entry_points.txt");
+                fs_err::read_to_string(&path)
+                    .ok()
+                    .and_then(|entry_points| parse_console_scripts(&entry_points))
+            } else {
+                None
+            };
+
+            reports.push(ShowReport {
+                name: distribution.name().to_string(),
+                version: distribution.version().to_string(),
+                location: distribution
+                    .install_path()
+                    .parent()
+                    .expect("package path is not root")
+                    .simplified_display()
+                    .to_string(),
+                editable_project_location: distribution
+                    .as_editable()
+                    .and_then(|url| url.to_file_path().ok())
+                    .map(|path| path.simplified_display().to_string()),
+                summary: metadata.as_ref().and_then(|m| m.summary.clone()),
+                home_page: metadata.as_ref().and_then(|m| m.home_page.clone()),
+                author: metadata.as_ref().and_then(|m| m.author.clone()),
+                author_email: metadata.as_ref().and_then(|m| m.author_email.clone()),
+                license: metadata.as_ref().and_then(|m| m.license.clone()),
+                requires_python: metadata
+                    .as_ref()
+                    .and_then(|m| m.requires_python.as_ref())
+                    .map(ToString::to_string),
+                project_urls,
+                classifiers,
+                entry_points,
+                requires: requires_map
+                    .get(distribution.name())
+                    .cloned()
+                    .unwrap_or_default()
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect_vec(),
+                required_by: required_by(distribution.name())
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect_vec(),
+                required_by_tree: tree.then(|| {
+                    let mut path = vec![distribution.name().clone()];
+                    build_required_by_tree(&required_by, distribution.name(), &mut path)
+                }),
+                files,
+                verify: verify_report,
+            });
         }
 
-        // Print the name, version, and location (e.g., the `site-packages` directory).
-        writeln!(printer.stdout(), "Name: {}", distribution.name())?;
-        writeln!(printer.stdout(), "Version: {}", distribution.version())?;
         writeln!(
             printer.stdout(),
-            "Location: {}",
-            distribution
-                .install_path()
-                .parent()
-                .expect("package path is not root")
-                .simplified_display()
+            "{}",
+            serde_json::to_string_pretty(&reports)?
         )?;
+    } else {
+        // Print the information for each package.
+        for (i, distribution) in distributions.iter().enumerate() {
+            if i > 0 {
+                // Print a separator between packages.
+                writeln!(printer.stdout(), "---")?;
+            }
+
+            // Print the name, version, and location (e.g., the `site-packages` directory).
+            writeln!(printer.stdout(), "Name: {}", distribution.name())?;
+            writeln!(printer.stdout(), "Version: {}", distribution.version())?;
+
+            // Print the remaining core-metadata fields, matching pip's header format.
+            if let Ok(metadata) = distribution.metadata() {
+                writeln!(
+                    printer.stdout(),
+                    "Summary: {}",
+                    metadata.summary.as_deref().unwrap_or_default()
+                )?;
+                writeln!(
+                    printer.stdout(),
+                    "Home-page: {}",
+                    metadata.home_page.as_deref().unwrap_or_default()
+                )?;
+                writeln!(
+                    printer.stdout(),
+                    "Author: {}",
+                    metadata.author.as_deref().unwrap_or_default()
+                )?;
+                writeln!(
+                    printer.stdout(),
+                    "Author-email: {}",
+                    metadata.author_email.as_deref().unwrap_or_default()
+                )?;
+                writeln!(
+                    printer.stdout(),
+                    "License: {}",
+                    metadata.license.as_deref().unwrap_or_default()
+                )?;
+                if let Some(requires_python) = metadata.requires_python.as_ref() {
+                    writeln!(printer.stdout(), "Requires-Python: {requires_python}")?;
+                }
+            }
 
-        if let Some(path) = distribution
-            .as_editable()
-            .and_then(|url| url.to_file_path().ok())
-        {
             writeln!(
                 printer.stdout(),
-                "Editable project location: {}",
-                path.simplified_display()
+                "Location: {}",
+                distribution
+                    .install_path()
+                    .parent()
+                    .expect("package path is not root")
+                    .simplified_display()
             )?;
-        }
 
-        // If available, print the requirements.
-        if let Some(requires) = requires_map.get(distribution.name()) {
-            if requires.is_empty() {
-                writeln!(printer.stdout(), "Requires:")?;
-            } else {
-                writeln!(printer.stdout(), "Requires: {}", requires.iter().join(", "))?;
-            }
-
-            let required_by = requires_map
-                .iter()
-                .filter(|(name, pkgs)| {
-                    **name != distribution.name()
-                        && pkgs.iter().any(|pkg| pkg == distribution.name())
-                })
-                .map(|(name, _)| name)
-                .sorted_unstable()
-                .dedup()
-                .collect_vec();
-            if required_by.is_empty() {
-                writeln!(printer.stdout(), "Required-by:")?;
-            } else {
+            if let Some(path) = distribution
+                .as_editable()
+                .and_then(|url| url.to_file_path().ok())
+            {
                 writeln!(
                     printer.stdout(),
-                    "Required-by: {}",
-                    required_by.into_iter().join(", "),
+                    "Editable project location: {}",
+                    path.simplified_display()
                 )?;
             }
-        }
 
-        // If requests, show the list of installed files.
-        if files {
-            let path = distribution.install_path().join("RECORD");
-            let record = read_record_file(&mut File::open(path)?)?;
-            writeln!(printer.stdout(), "Files:")?;
-            for entry in record {
-                writeln!(printer.stdout(), "  {}", entry.path)?;
+            // If available, print the requirements.
+            if let Some(requires) = requires_map.get(distribution.name()) {
+                if requires.is_empty() {
+                    writeln!(printer.stdout(), "Requires:")?;
+                } else {
+                    writeln!(printer.stdout(), "Requires: {}", requires.iter().join(", "))?;
+                }
+
+                let immediate_required_by = required_by(distribution.name());
+                if immediate_required_by.is_empty() {
+                    writeln!(printer.stdout(), "Required-by:")?;
+                } else {
+                    writeln!(
+                        printer.stdout(),
+                        "Required-by: {}",
+                        immediate_required_by.iter().join(", ")
+                    )?;
+                }
+            }
+
+            // In `--tree` mode, render the full, transitive reverse-dependency tree, marking
+            // cycles rather than recursing into them forever.
+            if tree {
+                writeln!(printer.stdout(), "Required-by tree:")?;
+                let mut path = vec![distribution.name().clone()];
+                let nodes = build_required_by_tree(&required_by, distribution.name(), &mut path);
+                write_required_by_tree(printer, &nodes, 1)?;
+            }
+
+            // In `--verbose` mode, also print the project URLs, classifiers, and console
+            // scripts declared by the distribution's entry points.
+            if verbose {
+                if let Ok(metadata) = distribution.metadata() {
+                    for (name, url) in &metadata.project_urls {
+                        writeln!(printer.stdout(), "Project-URL: {name}, {url}")?;
+                    }
+
+                    if !metadata.classifiers.is_empty() {
+                        writeln!(printer.stdout(), "Classifiers:")?;
+                        for classifier in &metadata.classifiers {
+                            writeln!(printer.stdout(), "  {classifier}")?;
+                        }
+                    }
+                }
+
+                let entry_points_path = distribution.install_path().join("entry_points.txt");
+                if let Ok(entry_points) = fs_err::read_to_string(&entry_points_path) {
+                    if let Some(console_scripts) = parse_console_scripts(&entry_points) {
+                        writeln!(printer.stdout(), "Entry-points:")?;
+                        for script in console_scripts {
+                            writeln!(printer.stdout(), "  {script}")?;
+                        }
+                    }
+                }
+            }
+
+            // If requests, show the list of installed files.
+            if files {
+                let path = distribution.install_path().join("RECORD");
+                let record = read_record_file(&mut File::open(path)?)?;
+                writeln!(printer.stdout(), "Files:")?;
+                for entry in record {
+                    writeln!(printer.stdout(), "  {}", entry.path)?;
+                }
+            }
+
+            // If requested, verify the installed files against the RECORD's declared hashes
+            // and sizes, the way a package verifier would.
+            if verify {
+                let path = distribution.install_path().join("RECORD");
+                let record = read_record_file(&mut File::open(path)?)?;
+                let report = verify_record(distribution.install_path(), &record)?;
+
+                writeln!(
+                    printer.stdout(),
+                    "Verify: {} OK, {} missing, {} corrupt, {} skipped",
+                    report.ok,
+                    report.missing.len(),
+                    report.corrupt.len(),
+                    report.skipped
+                )?;
+                for path in &report.missing {
+                    writeln!(printer.stdout(), "  missing: {path}")?;
+                }
+                for path in &report.corrupt {
+                    writeln!(printer.stdout(), "  corrupt: {path}")?;
+                }
+
+                if report.is_failure() {
+                    verification_failed = true;
+                }
             }
         }
     }
@@ -211,5 +496,468 @@ pub(crate) fn pip_show(
         }
     }
 
+    if verification_failed {
+        return Ok(ExitStatus::Failure);
+    }
+
     Ok(ExitStatus::Success)
 }
+
+/// Show, for each of `packages`, every discovered virtual environment it's installed in along
+/// with its version there. Turns `uv pip show` into a cross-environment locator.
+fn pip_show_all_environments(
+    packages: &[PackageName],
+    environments_root: Option<&Path>,
+    output_format: ShowFormat,
+    cache: &Cache,
+    printer: Printer,
+) -> Result<ExitStatus> {
+    let candidates = discover_environments(environments_root);
+
+    let mut environments = Vec::with_capacity(candidates.len());
+    for root in candidates {
+        let Ok(environment) = PythonEnvironment::from_root(&root, cache) else {
+            continue;
+        };
+        let Ok(site_packages) = SitePackages::from_environment(&environment) else {
+            continue;
+        };
+        environments.push((root, site_packages));
+    }
+
+    if environments.is_empty() {
+        writeln!(
+            printer.stderr(),
+            "{}{} No virtual environments found.",
+            "warning".yellow().bold(),
+            ":".bold(),
+        )?;
+        return Ok(ExitStatus::Failure);
+    }
+
+    let mut any_found = false;
+    let mut reports = Vec::with_capacity(packages.len());
+    for name in packages {
+        let mut found = Vec::new();
+        for (root, site_packages) in &environments {
+            for installed in site_packages.get_packages(name) {
+                found.push((root, installed.version().to_string()));
+            }
+        }
+
+        if !found.is_empty() {
+            any_found = true;
+        }
+
+        if output_format == ShowFormat::Json {
+            reports.push(AllEnvironmentsReport {
+                name: name.to_string(),
+                installations: found
+                    .iter()
+                    .map(|(root, version)| EnvironmentInstallation {
+                        environment: root.simplified_display().to_string(),
+                        version: version.clone(),
+                    })
+                    .collect_vec(),
+            });
+        } else {
+            writeln!(printer.stdout(), "{name}:")?;
+            if found.is_empty() {
+                writeln!(
+                    printer.stdout(),
+                    "  not installed in any discovered environment"
+                )?;
+            } else {
+                for (root, version) in found {
+                    writeln!(
+                        printer.stdout(),
+                        "  {}: {version}",
+                        root.simplified_display()
+                    )?;
+                }
+            }
+        }
+    }
+
+    if output_format == ShowFormat::Json {
+        writeln!(
+            printer.stdout(),
+            "{}",
+            serde_json::to_string_pretty(&reports)?
+        )?;
+    }
+
+    if any_found {
+        Ok(ExitStatus::Success)
+    } else {
+        Ok(ExitStatus::Failure)
+    }
+}
+
+/// A single queried package, as rendered by `--all-environments --format json`.
+#[derive(Debug, Serialize)]
+struct AllEnvironmentsReport {
+    name: String,
+    installations: Vec<EnvironmentInstallation>,
+}
+
+/// A single environment a package was found installed in.
+#[derive(Debug, Serialize)]
+struct EnvironmentInstallation {
+    environment: String,
+    version: String,
+}
+
+/// Discover candidate virtual environment roots: the project `.venv`, `VIRTUAL_ENV`, and every
+/// subdirectory of `environments_root` (if provided), keeping only those that look like a venv
+/// (i.e., contain a `pyvenv.cfg`) and deduplicating by canonical path, so a `.venv` that's also
+/// pointed to by `VIRTUAL_ENV` isn't scanned or reported twice under different spellings.
+fn discover_environments(environments_root: Option<&Path>) -> Vec<PathBuf> {
+    let mut candidates = vec![PathBuf::from(".venv")];
+
+    if let Ok(virtual_env) = std::env::var(EnvVars::VIRTUAL_ENV) {
+        candidates.push(PathBuf::from(virtual_env));
+    }
+
+    if let Some(root) = environments_root {
+        if let Ok(entries) = fs_err::read_dir(root) {
+            for entry in entries.flatten() {
+                candidates.push(entry.path());
+            }
+        }
+    }
+
+    candidates
+        .into_iter()
+        .filter(|path| path.join("pyvenv.cfg").is_file())
+        .unique_by(|path| fs_err::canonicalize(path).unwrap_or_else(|_| path.clone()))
+        .collect()
+}
+
+/// The result of verifying a distribution's installed files against its RECORD.
+#[derive(Debug, Serialize)]
+struct VerifyReport {
+    /// The number of files that matched their recorded hash and size.
+    ok: usize,
+    /// Files declared in the RECORD but missing from disk.
+    missing: Vec<String>,
+    /// Files present on disk but with a mismatched size or hash.
+    corrupt: Vec<String>,
+    /// Files with no recorded hash or size (e.g., the RECORD file itself), or whose hash isn't
+    /// SHA-256, which can't be verified.
+    skipped: usize,
+}
+
+impl VerifyReport {
+    /// Whether any file was found to be missing or corrupt.
+    fn is_failure(&self) -> bool {
+        !self.missing.is_empty() || !self.corrupt.is_empty()
+    }
+}
+
+/// Verify each file declared in a distribution's RECORD against the file on disk, recomputing
+/// its hash and comparing its size.
+fn verify_record(install_path: &Path, record: &[RecordEntry]) -> Result<VerifyReport> {
+    let mut report = VerifyReport {
+        ok: 0,
+        missing: Vec::new(),
+        corrupt: Vec::new(),
+        skipped: 0,
+    };
+
+    for entry in record {
+        let (Some(hash), Some(size)) = (entry.hash.as_ref(), entry.size) else {
+            report.skipped += 1;
+            continue;
+        };
+
+        let path = install_path.join(&entry.path);
+        let contents = match fs_err::read(&path) {
+            Ok(contents) => contents,
+            Err(_) => {
+                report.missing.push(entry.path.clone());
+                continue;
+            }
+        };
+
+        if contents.len() as u64 != size {
+            report.corrupt.push(entry.path.clone());
+            continue;
+        }
+
+        let Some(digest) = hash.strip_prefix("sha256=") else {
+            report.skipped += 1;
+            continue;
+        };
+        let expected = base64::engine::general_purpose::URL_SAFE_NO_PAD
+            .decode(digest)
+            .unwrap_or_default();
+
+        let actual = Sha256::digest(&contents);
+        if actual.as_slice() == expected.as_slice() {
+            report.ok += 1;
+        } else {
+            report.corrupt.push(entry.path.clone());
+        }
+    }
+
+    Ok(report)
+}
+
+/// Recursively build the tree of packages that (directly or indirectly) require `name`, marking
+/// any package already on the current path as a cycle instead of recursing into it, so diamond
+/// dependency graphs and mutual cycles terminate cleanly.
+fn build_required_by_tree(
+    required_by: &impl Fn(&PackageName) -> Vec<PackageName>,
+    name: &PackageName,
+    path: &mut Vec<PackageName>,
+) -> Vec<RequiredByNode> {
+    required_by(name)
+        .into_iter()
+        .map(|parent| {
+            if path.contains(&parent) {
+                return RequiredByNode {
+                    name: parent.to_string(),
+                    cycle: true,
+                    children: Vec::new(),
+                };
+            }
+
+            path.push(parent.clone());
+            let children = build_required_by_tree(required_by, &parent, path);
+            path.pop();
+
+            RequiredByNode {
+                name: parent.to_string(),
+                cycle: false,
+                children,
+            }
+        })
+        .collect()
+}
+
+/// Print a `--tree` reverse-dependency tree built by [`build_required_by_tree`], indenting each
+/// level and marking cycles with `(*)`.
+fn write_required_by_tree(printer: Printer, nodes: &[RequiredByNode], depth: usize) -> Result<()> {
+    let indent = "  ".repeat(depth);
+    for node in nodes {
+        if node.cycle {
+            writeln!(printer.stdout(), "{indent}{} (*)", node.name)?;
+        } else {
+            writeln!(printer.stdout(), "{indent}{}", node.name)?;
+            write_required_by_tree(printer, &node.children, depth + 1)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Parse the `[console_scripts]` section of an `entry_points.txt` file, returning each entry
+/// line as written (e.g., `name = module:function`), or `None` if the section has no entries.
+fn parse_console_scripts(entry_points: &str) -> Option<Vec<String>> {
+    let mut scripts = Vec::new();
+    let mut in_console_scripts = false;
+    for line in entry_points.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if let Some(section) = line.strip_prefix('[').and_then(|s| s.strip_suffix(']')) {
+            in_console_scripts = section == "console_scripts";
+            continue;
+        }
+        if in_console_scripts {
+            scripts.push(line.to_string());
+        }
+    }
+
+    if scripts.is_empty() {
+        None
+    } else {
+        Some(scripts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    /// `--format json` should serialize a resolved distribution as an object keyed by the fields
+    /// documented for scripts to consume, rather than the `---`-separated text blocks.
+    #[test]
+    fn format_json_serializes_expected_fields() {
+        let report = ShowReport {
+            name: "example".to_string(),
+            version: "1.0.0".to_string(),
+            location: "/env/lib/site-packages".to_string(),
+            editable_project_location: None,
+            summary: None,
+            home_page: None,
+            author: None,
+            author_email: None,
+            license: None,
+            requires_python: None,
+            project_urls: None,
+            classifiers: None,
+            entry_points: None,
+            requires: vec!["dep-a".to_string()],
+            required_by: vec!["dep-b".to_string()],
+            required_by_tree: None,
+            files: Some(vec!["example/__init__.py".to_string()]),
+            verify: None,
+        };
+
+        let value: serde_json::Value = serde_json::to_value(&report).unwrap();
+        assert_eq!(value["name"], "example");
+        assert_eq!(value["version"], "1.0.0");
+        assert_eq!(value["location"], "/env/lib/site-packages");
+        assert_eq!(value["requires"], serde_json::json!(["dep-a"]));
+        assert_eq!(value["required_by"], serde_json::json!(["dep-b"]));
+        assert_eq!(value["files"], serde_json::json!(["example/__init__.py"]));
+    }
+
+    /// `--verbose` prints console scripts parsed from the `[console_scripts]` section of
+    /// `entry_points.txt`, ignoring unrelated sections like `[other_section]`.
+    #[test]
+    fn verbose_parses_console_scripts_section_only() {
+        let entry_points = "\
+[console_scripts]
+foo = foo.cli:main
+bar = bar.cli:run
+
+[other_section]
+plugin = other.plugin:register
+";
+
+        let scripts = parse_console_scripts(entry_points).unwrap();
+        assert_eq!(
+            scripts,
+            vec![
+                "foo = foo.cli:main".to_string(),
+                "bar = bar.cli:run".to_string(),
+            ]
+        );
+    }
+
+    /// With no `[console_scripts]` section, there's nothing to show under `--verbose`.
+    #[test]
+    fn verbose_no_console_scripts_section_returns_none() {
+        let entry_points = "[other_section]\nplugin = other.plugin:register\n";
+        assert_eq!(parse_console_scripts(entry_points), None);
+    }
+
+    /// `--verify` should report each RECORD entry as ok, missing, corrupt, or skipped, matching
+    /// the on-disk contents against the recorded hash and size.
+    #[test]
+    fn verify_classifies_ok_missing_corrupt_and_skipped() {
+        let install_path =
+            std::env::temp_dir().join("uv-pip-show-test-verify-classifies-ok-missing-corrupt");
+        fs_err::create_dir_all(&install_path).unwrap();
+
+        let ok_contents = b"print('hello')";
+        let ok_digest = Sha256::digest(ok_contents);
+        let ok_hash = format!(
+            "sha256={}",
+            base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(ok_digest)
+        );
+        fs_err::write(install_path.join("ok.py"), ok_contents).unwrap();
+        fs_err::write(install_path.join("corrupt.py"), b"print('tampered')").unwrap();
+
+        let record = vec![
+            RecordEntry {
+                path: "ok.py".to_string(),
+                hash: Some(ok_hash),
+                size: Some(ok_contents.len() as u64),
+            },
+            RecordEntry {
+                path: "missing.py".to_string(),
+                hash: Some("sha256=does-not-matter".to_string()),
+                size: Some(0),
+            },
+            RecordEntry {
+                path: "corrupt.py".to_string(),
+                hash: Some("sha256=AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA".to_string()),
+                size: Some(17),
+            },
+            RecordEntry {
+                path: "RECORD".to_string(),
+                hash: None,
+                size: None,
+            },
+        ];
+
+        let report = verify_record(&install_path, &record).unwrap();
+        fs_err::remove_dir_all(&install_path).unwrap();
+
+        assert_eq!(report.ok, 1);
+        assert_eq!(report.missing, vec!["missing.py".to_string()]);
+        assert_eq!(report.corrupt, vec!["corrupt.py".to_string()]);
+        assert_eq!(report.skipped, 1);
+        assert!(report.is_failure());
+    }
+
+    /// `--tree` should walk the reverse-dependency graph transitively and mark a package already
+    /// on the current path as a cycle instead of recursing into it forever.
+    #[test]
+    fn tree_marks_cycles_instead_of_recursing_forever() {
+        let a = PackageName::from_str("a").unwrap();
+        let b = PackageName::from_str("b").unwrap();
+        let c = PackageName::from_str("c").unwrap();
+
+        // a requires b, b requires c, c requires a: a cycle through all three.
+        let required_by = |name: &PackageName| -> Vec<PackageName> {
+            if *name == a {
+                vec![c.clone()]
+            } else if *name == b {
+                vec![a.clone()]
+            } else if *name == c {
+                vec![b.clone()]
+            } else {
+                Vec::new()
+            }
+        };
+
+        let mut path = vec![a.clone()];
+        let tree = build_required_by_tree(&required_by, &a, &mut path);
+
+        assert_eq!(tree.len(), 1);
+        assert_eq!(tree[0].name, "c");
+        assert!(!tree[0].cycle);
+        assert_eq!(tree[0].children.len(), 1);
+        assert_eq!(tree[0].children[0].name, "b");
+        assert!(!tree[0].children[0].cycle);
+        assert_eq!(tree[0].children[0].children.len(), 1);
+
+        let innermost = &tree[0].children[0].children[0];
+        assert_eq!(innermost.name, "a");
+        assert!(innermost.cycle);
+        assert!(innermost.children.is_empty());
+    }
+
+    /// `--all-environments` should dedup discovered roots by canonical path, so a symlink
+    /// pointing at an already-discovered venv isn't scanned or reported a second time.
+    #[test]
+    fn discover_environments_dedups_by_canonical_path() {
+        let root = std::env::temp_dir().join("uv-pip-show-test-discover-environments-dedup");
+        fs_err::create_dir_all(&root).unwrap();
+
+        let real_env = root.join("env");
+        fs_err::create_dir_all(&real_env).unwrap();
+        fs_err::write(real_env.join("pyvenv.cfg"), "").unwrap();
+
+        let linked_env = root.join("env-link");
+        std::os::unix::fs::symlink(&real_env, &linked_env).unwrap();
+
+        let candidates = discover_environments(Some(&root));
+
+        let canonical_real_env = fs_err::canonicalize(&real_env).unwrap();
+        let matches = candidates
+            .iter()
+            .filter(|path| fs_err::canonicalize(path).ok().as_ref() == Some(&canonical_real_env))
+            .count();
+        fs_err::remove_dir_all(&root).unwrap();
+        assert_eq!(matches, 1);
+    }
+}